@@ -0,0 +1,282 @@
+//! In-memory, height-indexed cache of committed blocks.
+//!
+//! Iroha's query API has no way to look a block up by height or a
+//! transaction up by hash, so the block explorer has to build that index
+//! itself. [`BlockStore`] is kept up to date by background tasks spawned by
+//! [`BlockStore::spawn_sync`] — one indexing newly committed blocks, another
+//! forwarding live pipeline/transaction events — and fans both kinds of
+//! event out to anyone subscribed via [`BlockStore::subscribe`], which is
+//! what the `GET /api/v1/events` SSE endpoint uses.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use futures::StreamExt;
+use iroha_crypto::Hash;
+use iroha_data_model::{
+    block::VersionedCommittedBlock,
+    events::pipeline::{PipelineEntityKind, PipelineEventFilter, PipelineStatus},
+    prelude::FilterBox,
+    transaction::TransactionValue,
+};
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+/// Bounded broadcast channel capacity for live notifications. Slow
+/// subscribers that fall this far behind miss old events rather than stall
+/// the sender.
+const BROADCAST_CAPACITY: usize = 64;
+
+/// A live event fanned out to [`BlockStore::subscribe`]rs.
+#[derive(Clone)]
+pub enum BlockStoreEvent {
+    /// A block was committed and indexed.
+    Block(Arc<VersionedCommittedBlock>),
+    /// Iroha reported a pipeline status change for a transaction — e.g.
+    /// validated, committed or rejected — independent of (and usually ahead
+    /// of) that transaction's block ever showing up in [`BlockStore::get`].
+    Transaction { hash: Hash, status: PipelineStatus },
+}
+
+impl BlockStoreEvent {
+    fn from_pipeline_event(event: iroha_data_model::events::pipeline::PipelineEvent) -> Option<Self> {
+        match event.entity_kind {
+            PipelineEntityKind::Transaction => Some(Self::Transaction {
+                hash: event.hash,
+                status: event.status,
+            }),
+            PipelineEntityKind::Block => None,
+        }
+    }
+}
+
+struct Inner {
+    by_height: BTreeMap<u64, Arc<VersionedCommittedBlock>>,
+    tx_index: HashMap<Hash, (u64, TransactionValue)>,
+    last_synced_height: u64,
+}
+
+/// Thread-safe cache of committed blocks, shared across the app via
+/// [`super::web::AppData`].
+#[derive(Clone)]
+pub struct BlockStore {
+    inner: Arc<RwLock<Inner>>,
+    events: broadcast::Sender<Arc<BlockStoreEvent>>,
+}
+
+/// Cancels the background sync tasks spawned by [`BlockStore::spawn_sync`].
+/// Dropping this instead of calling [`Self::cancel`] leaves both tasks
+/// running, same as before this handle existed.
+pub struct SyncHandle {
+    stop: Arc<AtomicBool>,
+    cancel_token: CancellationToken,
+    blocks_task: tokio::task::JoinHandle<()>,
+    events_task: tokio::task::JoinHandle<()>,
+}
+
+impl SyncHandle {
+    /// Signals both background tasks to stop and waits for them to exit.
+    ///
+    /// The block-indexing task polls a flag between blocks (and between
+    /// reconnect attempts), so it stops promptly but not instantly — it
+    /// can't be interrupted mid-read any more than
+    /// [`crate::iroha_client_wrap::run_on_dedicated_thread`] can. The
+    /// event-forwarding task checks a [`CancellationToken`], which (unlike
+    /// `tokio::sync::Notify::notify_waiters`) has no lost-wakeup window: a
+    /// `cancel()` call that lands before the task first awaits
+    /// `cancelled()` is still observed, since cancellation is a state the
+    /// token remembers rather than a one-shot wakeup.
+    pub async fn cancel(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        self.cancel_token.cancel();
+        let _ = self.blocks_task.await;
+        let _ = self.events_task.await;
+    }
+}
+
+impl BlockStore {
+    pub fn new() -> Self {
+        let (events, _) = broadcast::channel(BROADCAST_CAPACITY);
+        Self {
+            inner: Arc::new(RwLock::new(Inner {
+                by_height: BTreeMap::new(),
+                tx_index: HashMap::new(),
+                last_synced_height: 0,
+            })),
+            events,
+        }
+    }
+
+    /// Indexes a newly committed block and notifies subscribers.
+    pub fn insert(&self, block: VersionedCommittedBlock) {
+        let block = Arc::new(block);
+        let payload = block.as_v1();
+        let height = payload.header.height;
+
+        let mut inner = self.inner.write().expect("block store lock poisoned");
+        for tx in &payload.transactions {
+            inner.tx_index.insert(tx.hash(), (height, tx.clone()));
+        }
+        inner.by_height.insert(height, Arc::clone(&block));
+        inner.last_synced_height = inner.last_synced_height.max(height);
+        drop(inner);
+
+        // No receivers being subscribed yet is not an error.
+        let _ = self.events.send(Arc::new(BlockStoreEvent::Block(block)));
+    }
+
+    /// The height of the most recently indexed block, or `0` if none has
+    /// been indexed yet.
+    fn last_synced_height(&self) -> u64 {
+        self.inner
+            .read()
+            .expect("block store lock poisoned")
+            .last_synced_height
+    }
+
+    /// Looks up a single block by height.
+    pub fn get(&self, height: u64) -> Option<Arc<VersionedCommittedBlock>> {
+        self.inner
+            .read()
+            .expect("block store lock poisoned")
+            .by_height
+            .get(&height)
+            .cloned()
+    }
+
+    /// Looks up a single transaction by hash, together with the height of the
+    /// block it was committed in.
+    pub fn get_transaction(&self, hash: Hash) -> Option<(u64, TransactionValue)> {
+        self.inner
+            .read()
+            .expect("block store lock poisoned")
+            .tx_index
+            .get(&hash)
+            .cloned()
+    }
+
+    /// Returns up to `limit` blocks starting `offset` blocks back from the
+    /// chain tip, newest first, together with the total number of indexed
+    /// blocks.
+    pub fn page(&self, offset: usize, limit: usize) -> (Vec<Arc<VersionedCommittedBlock>>, u64) {
+        let inner = self.inner.read().expect("block store lock poisoned");
+        let total = inner.by_height.len() as u64;
+        let page = inner
+            .by_height
+            .values()
+            .rev()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect();
+        (page, total)
+    }
+
+    /// Subscribes to live notifications of newly committed blocks and
+    /// pipeline/transaction events.
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<BlockStoreEvent>> {
+        self.events.subscribe()
+    }
+
+    /// Spawns the background tasks that keep this store in sync with Iroha:
+    /// one indexing newly committed blocks, reconnecting the stream on error
+    /// and resuming from the last height this store actually indexed rather
+    /// than restarting from genesis; another forwarding Iroha's live
+    /// pipeline/transaction events. Returns a [`SyncHandle`] that can stop
+    /// both cleanly on shutdown.
+    pub fn spawn_sync(self, client: Arc<iroha_client::client::Client>) -> SyncHandle {
+        let stop = Arc::new(AtomicBool::new(false));
+        let cancel_token = CancellationToken::new();
+
+        let blocks_task = {
+            let store = self.clone();
+            let client = Arc::clone(&client);
+            let stop = Arc::clone(&stop);
+            tokio::task::spawn_blocking(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let from_height = store.last_synced_height() + 1;
+                    let stream = match client.listen_for_blocks(from_height) {
+                        Ok(stream) => stream,
+                        Err(err) => {
+                            tracing::error!(
+                                "Failed to subscribe to Iroha block stream from height {from_height}: {err}"
+                            );
+                            std::thread::sleep(std::time::Duration::from_secs(1));
+                            continue;
+                        }
+                    };
+                    for block in stream {
+                        if stop.load(Ordering::Relaxed) {
+                            return;
+                        }
+                        match block {
+                            Ok(block) => store.insert(block),
+                            Err(err) => {
+                                tracing::warn!("Iroha block stream error: {err}");
+                                break;
+                            }
+                        }
+                    }
+                }
+            })
+        };
+
+        let events_task = {
+            let store = self.clone();
+            let cancel_token = cancel_token.clone();
+            tokio::spawn(async move {
+                'reconnect: loop {
+                    let mut stream = tokio::select! {
+                        _ = cancel_token.cancelled() => return,
+                        stream = connect_pipeline_events(&client) => stream,
+                    };
+
+                    loop {
+                        tokio::select! {
+                            _ = cancel_token.cancelled() => return,
+                            item = stream.next() => match item {
+                                Some(Ok(event)) => {
+                                    if let Some(event) = BlockStoreEvent::from_pipeline_event(event) {
+                                        let _ = store.events.send(Arc::new(event));
+                                    }
+                                }
+                                Some(Err(err)) => {
+                                    tracing::warn!("Iroha pipeline event stream error: {err}");
+                                    continue 'reconnect;
+                                }
+                                None => continue 'reconnect,
+                            },
+                        }
+                    }
+                }
+            })
+        };
+
+        SyncHandle {
+            stop,
+            cancel_token,
+            blocks_task,
+            events_task,
+        }
+    }
+}
+
+/// Retries [`iroha_client::client::Client::events_async`] until it connects,
+/// pausing between attempts so a down peer doesn't get hammered.
+async fn connect_pipeline_events(
+    client: &iroha_client::client::Client,
+) -> iroha_client::client::asynchronous::AsyncEventStream {
+    loop {
+        match client
+            .events_async(FilterBox::Pipeline(PipelineEventFilter::default()))
+            .await
+        {
+            Ok(stream) => return stream,
+            Err(err) => {
+                tracing::error!("Failed to subscribe to Iroha pipeline events: {err}");
+                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+            }
+        }
+    }
+}
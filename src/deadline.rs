@@ -0,0 +1,108 @@
+//! Per-request deadline middleware.
+//!
+//! Reads an optional `X-Request-Deadline` header — milliseconds since the
+//! Unix epoch by which the response must be sent — or falls back to
+//! `default_timeout` measured from the moment the request arrives. If the
+//! deadline has already passed when the request arrives, the middleware
+//! short-circuits to [`WebError::Timeout`] immediately instead of starting
+//! the handler; if it elapses mid-flight, the handler's future is dropped
+//! and the same error is returned.
+//!
+//! Borrowed from pict-rs's request-deadline approach.
+
+use std::future::{ready, Ready};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::HeaderName,
+    HttpMessage,
+};
+use futures_util::future::LocalBoxFuture;
+
+use crate::web::WebError;
+
+static DEADLINE_HEADER: HeaderName = HeaderName::from_static("x-request-deadline");
+
+/// `actix-web` middleware that bounds every request to a deadline, either
+/// client-supplied via `X-Request-Deadline` or `default_timeout` from
+/// request arrival.
+#[derive(Clone)]
+pub struct RequestDeadline {
+    default_timeout: Duration,
+}
+
+impl RequestDeadline {
+    pub fn new(default_timeout: Duration) -> Self {
+        Self { default_timeout }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequestDeadline
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = RequestDeadlineMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestDeadlineMiddleware {
+            service,
+            default_timeout: self.default_timeout,
+        }))
+    }
+}
+
+pub struct RequestDeadlineMiddleware<S> {
+    service: S,
+    default_timeout: Duration,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestDeadlineMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let deadline = req
+            .headers()
+            .get(&DEADLINE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+            .unwrap_or_else(|| SystemTime::now() + self.default_timeout);
+
+        let remaining = match deadline.duration_since(SystemTime::now()) {
+            Ok(remaining) => remaining,
+            // Deadline already passed: fail fast, don't even call the handler.
+            Err(_) => {
+                return Box::pin(async move { Err(WebError::Timeout.into()) });
+            }
+        };
+
+        let fut = self.service.call(req);
+        Box::pin(async move {
+            match tokio::time::timeout(remaining, fut).await {
+                Ok(result) => result,
+                // The handler's future is dropped here, which detaches from
+                // any in-flight `IrohaClientWrap` request it was awaiting —
+                // but does not stop it. See
+                // `iroha_client_wrap::run_on_dedicated_thread` for why that
+                // can't be done any more thoroughly than this.
+                Err(_elapsed) => Err(WebError::Timeout.into()),
+            }
+        })
+    }
+}
@@ -0,0 +1,340 @@
+//! Thin async wrapper around [`iroha_client::client::Client`].
+//!
+//! `iroha_client`'s `Client` is a blocking, synchronous client. The `web`
+//! module is built on `actix-web` and wants an async surface, so this module
+//! offloads every request onto its own OS thread (see
+//! [`run_on_dedicated_thread`]) and hands back a `Future`. Keeping this
+//! wrapper thin (and free of any `actix-web` types) keeps the Iroha SDK
+//! boundary in one place.
+//!
+//! It also applies a [`tokio::sync::Semaphore`] that bounds how many
+//! requests may be in flight against the node at once (notion-client's note
+//! about "adding queuing"), and, inside the permit it holds, a retry policy
+//! with exponential backoff for transient [`ClientQueryError::Other`]
+//! failures and an injectable interceptor chain (for logging, response
+//! inspection — inspired by notion-client's `Callback`) that runs before
+//! every attempt and once after the last one.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+
+use iroha_client::client::{Client, ClientQueryError, Query, QueryResult};
+use iroha_data_model::query::{predicate::PredicateBox, Pagination, Sorting};
+use iroha_telemetry::metrics::Status;
+
+/// Runs `f` to completion on its own OS thread and returns its result,
+/// without tying up a thread from tokio's shared blocking-task pool.
+///
+/// [`crate::deadline`] races the `Future` this crate returns against a
+/// timeout and drops it if the deadline elapses first — but dropping the
+/// awaiting `Future` can't interrupt a blocking Iroha network call already
+/// in progress; the underlying socket read has no way to be told to stop.
+/// Using a dedicated thread instead of `tokio::task::spawn_blocking` at
+/// least keeps that unavoidable leftover thread (and its still-open socket)
+/// from counting against tokio's shared blocking-pool capacity, so a pile-up
+/// of abandoned slow queries can't starve unrelated `spawn_blocking` work
+/// elsewhere in the process. It is a bound on the blast radius, not true
+/// cancellation.
+async fn run_on_dedicated_thread<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    std::thread::spawn(move || {
+        // The receiver may already be gone if the caller stopped awaiting
+        // (e.g. the request deadline elapsed); that's not an error here.
+        let _ = tx.send(f());
+    });
+    rx.await
+        .expect("blocking Iroha client thread panicked before producing a result")
+}
+
+/// Observes (and may otherwise react to) every outgoing Iroha request.
+/// Interceptors are called before each attempt is dispatched and with a
+/// debug view of the output after the request completes, so a single
+/// object-safe trait can front logging and response-inspection use cases
+/// alike.
+#[async_trait::async_trait]
+pub trait RequestInterceptor: Send + Sync {
+    /// Called just before each attempt of a request is dispatched.
+    ///
+    /// `ctx.headers` starts out empty and interceptors may insert headers
+    /// into it, but `run` discards them: they are not attached to the
+    /// underlying call, which carries only the headers configured on
+    /// `iroha_client::client::Client` at construction.
+    async fn before_request(&self, ctx: &mut RequestContext) {
+        let _ = ctx;
+    }
+
+    /// Called once a request has completed, successfully or not. `response`
+    /// is a debug-formatted view of the typed output on success, so an
+    /// interceptor can inspect it without `run` giving up its generic `T`.
+    async fn after_request(&self, ctx: &ResponseContext) {
+        let _ = ctx;
+    }
+}
+
+/// Passed to [`RequestInterceptor::before_request`].
+pub struct RequestContext<'a> {
+    /// Identifies the Iroha query being run (its Rust type name, e.g.
+    /// `FindAllAccounts`).
+    pub label: &'a str,
+    /// Which attempt this is, `0`-indexed; nonzero means a previous attempt
+    /// failed and [`RetryPolicy`] is retrying it.
+    pub attempt: u32,
+    /// Headers an interceptor may set. They are not sent with the
+    /// underlying call; see [`RequestInterceptor::before_request`].
+    pub headers: http::HeaderMap,
+}
+
+/// Passed to [`RequestInterceptor::after_request`].
+pub struct ResponseContext<'a> {
+    pub label: &'a str,
+    pub succeeded: bool,
+    /// Debug-formatted view of the output, present only when the request
+    /// succeeded.
+    pub response: Option<&'a str>,
+}
+
+/// Retries a request with exponential backoff when it fails with
+/// [`ClientQueryError::Other`] — a transient transport-level error, as
+/// opposed to [`ClientQueryError::QueryError`], which Iroha rejected the
+/// query itself for and retrying would not fix.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn delay_for(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+
+    fn should_retry(&self, attempt: u32, err: &ClientQueryError) -> bool {
+        attempt + 1 < self.max_attempts && matches!(err, ClientQueryError::Other(_))
+    }
+}
+
+/// Builds an [`IrohaClientWrap`] with an interceptor chain, retry policy and
+/// concurrency limit on top of the defaults (no interceptors, no retries, 16
+/// concurrent requests).
+pub struct IrohaClientWrapBuilder {
+    client: Arc<Client>,
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    retry_policy: RetryPolicy,
+    max_concurrency: usize,
+}
+
+impl IrohaClientWrapBuilder {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self {
+            client,
+            interceptors: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            max_concurrency: 16,
+        }
+    }
+
+    /// Appends an interceptor to the end of the chain. Interceptors run in
+    /// the order they were added.
+    pub fn with_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Caps how many requests may be in flight against the node at once;
+    /// further requests queue on a [`tokio::sync::Semaphore`] permit.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    pub fn build(self) -> IrohaClientWrap {
+        IrohaClientWrap {
+            client: self.client,
+            interceptors: Arc::new(self.interceptors),
+            retry_policy: self.retry_policy,
+            concurrency: Arc::new(tokio::sync::Semaphore::new(self.max_concurrency)),
+        }
+    }
+}
+
+/// Wraps an [`iroha_client::client::Client`], exposing only what the `web`
+/// module needs and running every call on a blocking thread.
+#[derive(Clone)]
+pub struct IrohaClientWrap {
+    client: Arc<Client>,
+    interceptors: Arc<Vec<Arc<dyn RequestInterceptor>>>,
+    retry_policy: RetryPolicy,
+    concurrency: Arc<tokio::sync::Semaphore>,
+}
+
+impl IrohaClientWrap {
+    /// Creates a wrapper with no interceptors, no retries and a default
+    /// concurrency limit. Use [`IrohaClientWrapBuilder`] (via [`Self::builder`])
+    /// to customize any of that.
+    pub fn new(client: Arc<Client>) -> Self {
+        IrohaClientWrapBuilder::new(client).build()
+    }
+
+    pub fn builder(client: Arc<Client>) -> IrohaClientWrapBuilder {
+        IrohaClientWrapBuilder::new(client)
+    }
+
+    /// Runs `f` on a blocking thread, applying the interceptor chain, retry
+    /// policy and concurrency limit around it. `label` identifies the call
+    /// for interceptors; it does not affect behavior otherwise.
+    async fn run<T, F>(&self, label: &'static str, f: F) -> Result<T, ClientQueryError>
+    where
+        T: Debug + Send + 'static,
+        F: Fn(&Client) -> Result<T, ClientQueryError> + Send + Sync + 'static,
+    {
+        let _permit = self
+            .concurrency
+            .acquire()
+            .await
+            .expect("semaphore is never closed");
+
+        let f = Arc::new(f);
+        let mut attempt = 0;
+        let result = loop {
+            let mut ctx = RequestContext {
+                label,
+                attempt,
+                headers: http::HeaderMap::new(),
+            };
+            for interceptor in self.interceptors.iter() {
+                interceptor.before_request(&mut ctx).await;
+            }
+
+            let client = Arc::clone(&self.client);
+            let f = Arc::clone(&f);
+            let outcome = run_on_dedicated_thread(move || f(&client)).await;
+
+            match outcome {
+                Ok(value) => break Ok(value),
+                Err(err) if self.retry_policy.should_retry(attempt, &err) => {
+                    tokio::time::sleep(self.retry_policy.delay_for(attempt)).await;
+                    attempt += 1;
+                }
+                Err(err) => break Err(err),
+            }
+        };
+
+        let response_debug = result.as_ref().ok().map(|value| format!("{value:?}"));
+        let response_ctx = ResponseContext {
+            label,
+            succeeded: result.is_ok(),
+            response: response_debug.as_deref(),
+        };
+        for interceptor in self.interceptors.iter() {
+            interceptor.after_request(&response_ctx).await;
+        }
+
+        result
+    }
+
+    /// Runs `request` with no pagination, sorting or filtering applied.
+    pub async fn request<R>(&self, request: R) -> Result<R::Output, ClientQueryError>
+    where
+        R: Query + Debug + Send + Sync + Clone + 'static,
+        R::Output: Debug + Send + 'static,
+    {
+        self.run(std::any::type_name::<R>(), move |client| {
+            client.request(request.clone())
+        })
+        .await
+    }
+
+    /// Runs `request`, returning only the page described by `pagination`.
+    pub async fn request_with_pagination<R>(
+        &self,
+        request: R,
+        pagination: Pagination,
+    ) -> Result<QueryResult<R::Output>, ClientQueryError>
+    where
+        R: Query + Debug + Send + Sync + Clone + 'static,
+        R::Output: Debug + Send + 'static,
+    {
+        self.run(std::any::type_name::<R>(), move |client| {
+            client.request_with_pagination(request.clone(), pagination)
+        })
+        .await
+    }
+
+    /// Same as [`Self::request_with_pagination`], but additionally orders the
+    /// full result set according to `sorting` before paginating it.
+    pub async fn request_with_pagination_and_sorting<R>(
+        &self,
+        request: R,
+        pagination: Pagination,
+        sorting: Sorting,
+    ) -> Result<QueryResult<R::Output>, ClientQueryError>
+    where
+        R: Query + Debug + Send + Sync + Clone + 'static,
+        R::Output: Debug + Send + 'static,
+    {
+        self.run(std::any::type_name::<R>(), move |client| {
+            client.request_with_pagination_and_sorting(
+                request.clone(),
+                pagination,
+                sorting.clone(),
+            )
+        })
+        .await
+    }
+
+    /// Same as [`Self::request_with_pagination_and_sorting`], but additionally
+    /// restricts the result set to entries matching `filter` before sorting
+    /// and paginating it.
+    pub async fn request_with_filter_and_pagination_and_sorting<R>(
+        &self,
+        request: R,
+        pagination: Pagination,
+        sorting: Sorting,
+        filter: PredicateBox,
+    ) -> Result<QueryResult<R::Output>, ClientQueryError>
+    where
+        R: Query + Debug + Send + Sync + Clone + 'static,
+        R::Output: Debug + Send + 'static,
+    {
+        self.run(std::any::type_name::<R>(), move |client| {
+            client.request_with_filter_and_pagination_and_sorting(
+                request.clone(),
+                pagination,
+                sorting.clone(),
+                filter.clone(),
+            )
+        })
+        .await
+    }
+
+    /// Fetches the current peer status (block height, peer count, etc.).
+    pub async fn get_status(&self) -> color_eyre::Result<Status> {
+        self.run("GetStatus", |client| {
+            client.get_status().map_err(|err| {
+                ClientQueryError::Other(color_eyre::eyre::eyre!("{err}"))
+            })
+        })
+        .await
+        .map_err(|err| color_eyre::eyre::eyre!("{err}"))
+    }
+}
@@ -0,0 +1,145 @@
+//! Prometheus metrics: a `GET /metrics` endpoint, middleware that
+//! instruments every request, and a background task that turns Iroha's peer
+//! status into gauges.
+//!
+//! Modeled on pict-rs's use of `metrics-exporter-prometheus`.
+
+use std::future::{ready, Ready};
+use std::time::{Duration, Instant};
+
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    get, web, HttpResponse, Scope,
+};
+use futures_util::future::LocalBoxFuture;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+
+use crate::iroha_client_wrap::IrohaClientWrap;
+
+/// Installs the global Prometheus recorder. Must be called exactly once,
+/// before any metric is recorded.
+pub fn install_recorder() -> color_eyre::Result<PrometheusHandle> {
+    PrometheusBuilder::new()
+        .install_recorder()
+        .map_err(|err| color_eyre::eyre::eyre!("Failed to install Prometheus recorder: {err}"))
+}
+
+/// `GET /metrics` — renders the current metrics in Prometheus's text
+/// exposition format.
+#[get("/metrics")]
+async fn index(handle: web::Data<PrometheusHandle>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(handle.render())
+}
+
+pub fn service() -> Scope {
+    web::scope("").service(index)
+}
+
+/// `actix-web` middleware that records a request counter and a latency
+/// histogram per route template, method and status code, plus a single
+/// unlabeled in-flight gauge.
+#[derive(Clone, Default)]
+pub struct RequestMetrics;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestMetrics
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = RequestMetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestMetricsMiddleware { service }))
+    }
+}
+
+pub struct RequestMetricsMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestMetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        // `req.match_pattern()` would always be `None` here: actix-web only
+        // resolves the route template while dispatching to the inner
+        // service, so reading it before `call()` falls back to the concrete
+        // `req.path()` for every dynamic route (`/blocks/{height}`, ...),
+        // giving each distinct id its own unbounded-cardinality label. Read
+        // it off the response instead, once the router has actually run.
+        let fallback_path = req.path().to_owned();
+        let method = req.method().to_string();
+        let started_at = Instant::now();
+
+        // The route template isn't known until the router has matched, so
+        // the in-flight gauge can't be labeled by it without mismatching the
+        // increment/decrement series; track it unlabeled instead.
+        metrics::increment_gauge!("http_requests_in_flight", 1.0);
+
+        let fut = self.service.call(req);
+        Box::pin(async move {
+            let result = fut.await;
+            metrics::decrement_gauge!("http_requests_in_flight", 1.0);
+
+            let path = result
+                .as_ref()
+                .ok()
+                .and_then(|res| res.request().match_pattern())
+                .unwrap_or(fallback_path);
+
+            let status = result
+                .as_ref()
+                .map(|res| res.status().as_u16().to_string())
+                .unwrap_or_else(|_| "error".to_owned());
+
+            metrics::increment_counter!(
+                "http_requests_total",
+                "path" => path.clone(), "method" => method.clone(), "status" => status.clone()
+            );
+            metrics::histogram!(
+                "http_request_duration_seconds",
+                started_at.elapsed().as_secs_f64(),
+                "path" => path, "method" => method, "status" => status
+            );
+
+            result
+        })
+    }
+}
+
+/// Spawns a background task that periodically polls
+/// [`IrohaClientWrap::get_status`] and publishes peer/block/transaction
+/// counts as gauges, so they show up at `GET /metrics` without a client ever
+/// having to ask for them.
+pub fn spawn_status_poller(client: IrohaClientWrap, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match client.get_status().await {
+                Ok(status) => {
+                    metrics::gauge!("iroha_peers", status.peers as f64);
+                    metrics::gauge!("iroha_blocks", status.blocks as f64);
+                    metrics::gauge!("iroha_transactions_committed", status.txs as f64);
+                }
+                Err(err) => tracing::warn!("Failed to poll Iroha status for metrics: {err}"),
+            }
+        }
+    });
+}
@@ -0,0 +1,229 @@
+//! Optional HTTPS termination via `rustls`, with an SNI-aware certificate
+//! resolver that can be hot-swapped without restarting the server.
+//!
+//! Modeled on the rustls cert-resolver approach used in domiply and pict-rs.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use rustls::ServerConfig;
+
+/// How the server obtains its TLS certificate.
+pub enum TlsConfig {
+    /// Load a single certificate/key pair from disk once at startup; it
+    /// never changes for the lifetime of the process and is served to every
+    /// client regardless of the hostname it requested via SNI.
+    File {
+        cert_path: std::path::PathBuf,
+        key_path: std::path::PathBuf,
+    },
+    /// Load one certificate/key pair per SNI hostname, picking the one that
+    /// matches the `ClientHello`'s requested server name and falling back to
+    /// `default_hostname`'s cert when the client didn't send SNI, or named a
+    /// host we have no cert for.
+    SniFiles {
+        certs: Vec<(String, std::path::PathBuf, std::path::PathBuf)>,
+        default_hostname: Option<String>,
+    },
+    /// A caller-supplied resolver, e.g. [`HotSwapCertResolver`] fed by a file
+    /// watcher or ACME client.
+    Resolver(Arc<dyn ResolvesServerCert>),
+}
+
+impl TlsConfig {
+    /// Builds the `rustls` server config `server()` binds with.
+    pub fn into_server_config(self) -> color_eyre::Result<ServerConfig> {
+        // `load_certified_key` pins the `ring` signature backend and
+        // `ServerConfig::builder()` resolves the process-wide crypto
+        // provider; on rustls 0.23 both require one to already be installed,
+        // otherwise they panic (or race another installer). This is
+        // idempotent to call more than once, so it's safe even if some other
+        // TLS-using dependency already installed one.
+        let _ = rustls::crypto::CryptoProvider::install_default(
+            rustls::crypto::ring::default_provider(),
+        );
+
+        let resolver: Arc<dyn ResolvesServerCert> = match self {
+            Self::File {
+                cert_path,
+                key_path,
+            } => Arc::new(SingleCertResolver(load_certified_key(
+                &cert_path, &key_path,
+            )?)),
+            Self::SniFiles {
+                certs,
+                default_hostname,
+            } => {
+                let mut by_hostname = HashMap::with_capacity(certs.len());
+                for (hostname, cert_path, key_path) in certs {
+                    by_hostname.insert(
+                        hostname,
+                        Arc::new(load_certified_key(&cert_path, &key_path)?),
+                    );
+                }
+                Arc::new(SniCertResolver {
+                    by_hostname,
+                    default_hostname,
+                })
+            }
+            Self::Resolver(resolver) => resolver,
+        };
+
+        Ok(ServerConfig::builder()
+            .with_no_client_auth()
+            .with_cert_resolver(resolver))
+    }
+}
+
+fn load_certified_key(cert_path: &Path, key_path: &Path) -> color_eyre::Result<CertifiedKey> {
+    use color_eyre::eyre::Context;
+
+    let cert_chain = rustls_pemfile::certs(&mut std::io::BufReader::new(std::fs::File::open(
+        cert_path,
+    )?))
+    .collect::<Result<Vec<_>, _>>()
+    .wrap_err_with(|| format!("Failed to read certificate at {}", cert_path.display()))?;
+
+    let key = rustls_pemfile::private_key(&mut std::io::BufReader::new(std::fs::File::open(
+        key_path,
+    )?))
+    .wrap_err_with(|| format!("Failed to read private key at {}", key_path.display()))?
+    .ok_or_else(|| color_eyre::eyre::eyre!("No private key found in {}", key_path.display()))?;
+
+    let signing_key = rustls::crypto::ring::sign::any_supported_type(&key)
+        .wrap_err("Unsupported private key type")?;
+
+    Ok(CertifiedKey::new(cert_chain, signing_key))
+}
+
+/// Serves the same certificate to every client, independent of SNI. Used for
+/// [`TlsConfig::File`], which by design has only one certificate to offer.
+struct SingleCertResolver(CertifiedKey);
+
+impl std::fmt::Debug for SingleCertResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SingleCertResolver").finish()
+    }
+}
+
+impl ResolvesServerCert for SingleCertResolver {
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        Some(Arc::new(self.0.clone()))
+    }
+}
+
+/// Picks a certificate by the hostname the client requested via SNI, falling
+/// back to `default_hostname`'s cert when the `ClientHello` has no server
+/// name, or names a host we don't have a cert for.
+struct SniCertResolver {
+    by_hostname: HashMap<String, Arc<CertifiedKey>>,
+    default_hostname: Option<String>,
+}
+
+impl std::fmt::Debug for SniCertResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SniCertResolver")
+            .field("hostnames", &self.by_hostname.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl SniCertResolver {
+    fn resolve_by_hostname(&self, client_hello: &ClientHello) -> Option<Arc<CertifiedKey>> {
+        client_hello
+            .server_name()
+            .and_then(|hostname| self.by_hostname.get(hostname))
+            .or_else(|| {
+                self.default_hostname
+                    .as_deref()
+                    .and_then(|hostname| self.by_hostname.get(hostname))
+            })
+            .cloned()
+    }
+}
+
+impl ResolvesServerCert for SniCertResolver {
+    fn resolve(&self, client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        self.resolve_by_hostname(&client_hello)
+    }
+}
+
+/// An SNI-aware resolver whose certificates can be replaced at runtime via
+/// its paired [`TlsCertSender`] — per hostname, without restarting the
+/// server or dropping connections already established.
+#[derive(Clone)]
+pub struct HotSwapCertResolver {
+    state: Arc<RwLock<SniState>>,
+}
+
+struct SniState {
+    by_hostname: HashMap<String, Arc<CertifiedKey>>,
+    default_hostname: Option<String>,
+}
+
+impl std::fmt::Debug for HotSwapCertResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HotSwapCertResolver").finish()
+    }
+}
+
+impl HotSwapCertResolver {
+    /// Creates a resolver starting out with `initial` (one certificate per
+    /// hostname), together with the sender used to rotate individual
+    /// hostnames' certificates later. `default_hostname` names the cert
+    /// served to clients that don't send SNI, or ask for an unknown host.
+    pub fn new(
+        initial: HashMap<String, CertifiedKey>,
+        default_hostname: Option<String>,
+    ) -> (Self, TlsCertSender) {
+        let state = Arc::new(RwLock::new(SniState {
+            by_hostname: initial
+                .into_iter()
+                .map(|(hostname, cert)| (hostname, Arc::new(cert)))
+                .collect(),
+            default_hostname,
+        }));
+        (
+            Self {
+                state: Arc::clone(&state),
+            },
+            TlsCertSender { state },
+        )
+    }
+}
+
+impl ResolvesServerCert for HotSwapCertResolver {
+    fn resolve(&self, client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        let state = self.state.read().expect("lock poisoned");
+        client_hello
+            .server_name()
+            .and_then(|hostname| state.by_hostname.get(hostname))
+            .or_else(|| {
+                state
+                    .default_hostname
+                    .as_deref()
+                    .and_then(|hostname| state.by_hostname.get(hostname))
+            })
+            .cloned()
+    }
+}
+
+/// Rotates the certificates served by its paired [`HotSwapCertResolver`].
+pub struct TlsCertSender {
+    state: Arc<RwLock<SniState>>,
+}
+
+impl TlsCertSender {
+    /// Atomically swaps in a new certificate for `hostname`, affecting every
+    /// connection accepted from now on that requests it via SNI.
+    pub fn send(&self, hostname: String, cert: CertifiedKey) {
+        self.state
+            .write()
+            .expect("lock poisoned")
+            .by_hostname
+            .insert(hostname, Arc::new(cert));
+    }
+}
@@ -0,0 +1,67 @@
+//! Filtering query params accepted by every `index` endpoint.
+
+use std::collections::BTreeMap;
+
+use iroha_data_model::query::predicate::{
+    string::StringPredicate,
+    value::{Metadata as MetadataPredicate, ValuePredicate},
+    PredicateBox,
+};
+use serde::Deserialize;
+
+use super::WebError;
+
+const METADATA_FILTER_PREFIX: &str = "filter[metadata.";
+
+/// Query params accepted by every `index` endpoint for filtering a
+/// collection server-side:
+///
+/// - `?id_contains=<substr>` matches entries whose id contains `substr`.
+/// - `?filter[metadata.<key>]=<value>` (repeatable) matches entries whose
+///   metadata has `key` set to `value`.
+///
+/// Multiple filters are ANDed together.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct FilterQueryParams {
+    id_contains: Option<String>,
+    #[serde(flatten)]
+    rest: BTreeMap<String, String>,
+}
+
+impl FilterQueryParams {
+    /// Compiles the filter query params into a [`PredicateBox`], defaulting
+    /// to an always-true predicate when none were given.
+    pub fn into_predicate(self) -> Result<PredicateBox, WebError> {
+        let mut atoms = Vec::new();
+
+        if let Some(needle) = self.id_contains {
+            atoms.push(PredicateBox::Raw(ValuePredicate::Identifiable(
+                StringPredicate::contains(needle),
+            )));
+        }
+
+        // `web::Query<FilterQueryParams>` re-parses the whole query string
+        // independently of the sibling `PaginationQueryParams`/
+        // `SortingQueryParams` extractors, so `rest` also picks up keys like
+        // `page`/`per_page`/`sort_by` that belong to those. Only `filter[
+        // metadata.<key>]` entries are ours to compile; anything else is
+        // someone else's param and is silently ignored rather than rejected.
+        for (key, value) in self.rest {
+            let Some(metadata_key) = key
+                .strip_prefix(METADATA_FILTER_PREFIX)
+                .and_then(|rest| rest.strip_suffix(']'))
+            else {
+                continue;
+            };
+            atoms.push(PredicateBox::Raw(ValuePredicate::Metadata(
+                MetadataPredicate::has_key_value(metadata_key, value),
+            )));
+        }
+
+        Ok(match atoms.len() {
+            0 => PredicateBox::default(),
+            1 => atoms.remove(0),
+            _ => PredicateBox::And(atoms),
+        })
+    }
+}
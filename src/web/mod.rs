@@ -12,33 +12,51 @@ use iroha_core::smartcontracts::isi::query::Error as IrohaQueryError;
 use serde::Serialize;
 use std::{fmt, str::FromStr};
 
+use crate::block_store::BlockStore;
 use crate::iroha_client_wrap::IrohaClientWrap;
+use filter::FilterQueryParams;
 use pagination::{Paginated, PaginationQueryParams};
+use sorting::SortingQueryParams;
 
 /// Web app state that may be injected in runtime
 pub struct AppData {
     /// Pre-initialized Iroha Client
     iroha_client: IrohaClientWrap,
+    /// Height-indexed cache of committed blocks, kept up to date in the
+    /// background
+    blocks: BlockStore,
 }
 
 impl AppData {
     /// Creates new state with provided client
-    pub fn new(client: IrohaClientWrap) -> Self {
+    pub fn new(client: IrohaClientWrap, blocks: BlockStore) -> Self {
         Self {
             iroha_client: client,
+            blocks,
         }
     }
 }
 
 /// General error for all endpoints
 #[derive(Display, Debug)]
-enum WebError {
+pub(crate) enum WebError {
     /// Some error that should be logged, but shouldn't be returned to
     /// a client. Server should return an empty 500 error instead.
     Internal(color_eyre::Report),
     /// Some resource was not found.
     NotFound,
-    BadRequest(String),
+    /// A query string failed to parse (e.g. bad pagination or sorting
+    /// params).
+    InvalidQuery(String),
+    /// A `filter`/`id_contains` query param did not compile to a valid
+    /// `PredicateBox`.
+    InvalidFilter(String),
+    /// A path segment failed to parse (e.g. a malformed account id or
+    /// transaction hash).
+    InvalidPath(String),
+    /// The request's deadline elapsed, or had already passed when the
+    /// request arrived. See [`crate::deadline`].
+    Timeout,
 }
 
 impl WebError {
@@ -69,24 +87,80 @@ impl WebError {
     }
 }
 
+/// Stable, machine-readable identifier for a [`WebError`] variant. Part of
+/// the JSON response body, so these are API surface: renaming one is a
+/// breaking change for clients.
+impl WebError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Internal(_) => "INTERNAL",
+            Self::NotFound => "NOT_FOUND",
+            Self::InvalidQuery(_) => "INVALID_QUERY",
+            Self::InvalidFilter(_) => "INVALID_FILTER",
+            Self::InvalidPath(_) => "INVALID_PATH",
+            Self::Timeout => "TIMEOUT",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            // We don't want to expose internal errors to the client, so the
+            // report itself is omitted. `actix-web` will log it anyway.
+            Self::Internal(_) => "Internal Server Error".to_owned(),
+            Self::NotFound => "Not Found".to_owned(),
+            Self::InvalidQuery(msg) => format!("Invalid query: {msg}"),
+            Self::InvalidFilter(msg) => format!("Invalid filter: {msg}"),
+            Self::InvalidPath(msg) => format!("Invalid path: {msg}"),
+            Self::Timeout => "Request Timeout".to_owned(),
+        }
+    }
+
+    /// Optional JSON context included alongside the message. Never includes
+    /// internal error details — those are logged, not returned.
+    fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            Self::InvalidQuery(reason) | Self::InvalidFilter(reason) | Self::InvalidPath(reason) => {
+                Some(serde_json::json!({ "reason": reason }))
+            }
+            Self::Internal(_) | Self::NotFound | Self::Timeout => None,
+        }
+    }
+}
+
+/// JSON body of every error response:
+/// `{ "error": { "code", "message", "details" } }`.
+#[derive(Serialize)]
+struct ErrorEnvelope {
+    error: ErrorBody,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    code: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<serde_json::Value>,
+}
+
 impl ResponseError for WebError {
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::build(self.status_code())
-            .insert_header(http::header::ContentType::html())
-            .body(match self {
-                // We don't want to expose internal errors to the client, so here it is omitted.
-                // `actix-web` will log it anyway.
-                Self::Internal(_) => "Internal Server Error".to_owned(),
-                Self::NotFound => "Not Found".to_owned(),
-                Self::BadRequest(msg) => format!("Bad Request: {}", msg),
-            })
+        HttpResponse::build(self.status_code()).json(ErrorEnvelope {
+            error: ErrorBody {
+                code: self.code(),
+                message: self.message(),
+                details: self.details(),
+            },
+        })
     }
 
     fn status_code(&self) -> http::StatusCode {
         match self {
             Self::Internal(_) => http::StatusCode::INTERNAL_SERVER_ERROR,
             Self::NotFound => http::StatusCode::NOT_FOUND,
-            Self::BadRequest(_) => http::StatusCode::BAD_REQUEST,
+            Self::InvalidQuery(_) | Self::InvalidFilter(_) | Self::InvalidPath(_) => {
+                http::StatusCode::BAD_REQUEST
+            }
+            Self::Timeout => http::StatusCode::GATEWAY_TIMEOUT,
         }
     }
 }
@@ -103,12 +177,14 @@ impl From<iroha_data_model::ParseError> for WebError {
     }
 }
 
+mod filter;
 mod pagination;
+mod sorting;
 
 mod accounts {
     use super::{
-        assets::AssetDTO, fmt, get, web, AppData, Context, FromStr, Paginated,
-        PaginationQueryParams, Scope, Serialize, WebError,
+        assets::AssetDTO, fmt, get, web, AppData, Context, FilterQueryParams, FromStr, Paginated,
+        PaginationQueryParams, Scope, Serialize, SortingQueryParams, WebError,
     };
     use iroha_data_model::prelude::{
         Account, AccountId, FindAccountById, FindAllAccounts, Metadata,
@@ -199,10 +275,17 @@ mod accounts {
     async fn index(
         data: web::Data<AppData>,
         web::Query(pagination): web::Query<PaginationQueryParams>,
+        web::Query(sorting): web::Query<SortingQueryParams>,
+        web::Query(filter): web::Query<FilterQueryParams>,
     ) -> Result<web::Json<Paginated<Vec<AccountDTO>>>, WebError> {
         let paginated: Paginated<_> = data
             .iroha_client
-            .request_with_pagination(FindAllAccounts::new(), pagination.into())
+            .request_with_filter_and_pagination_and_sorting(
+                FindAllAccounts::new(),
+                pagination.into(),
+                sorting.into_sorting()?,
+                filter.into_predicate()?,
+            )
             .await
             .wrap_err("Failed to request for accounts")?
             .try_into()?;
@@ -219,8 +302,9 @@ mod accounts {
 
 mod domains {
     use super::{
-        accounts::AccountDTO, asset_definitions::AssetDefinitionDTO, get, web, AppData, Paginated,
-        PaginationQueryParams, Scope, Serialize, WebError,
+        accounts::AccountDTO, asset_definitions::AssetDefinitionDTO, get, web, AppData,
+        FilterQueryParams, Paginated, PaginationQueryParams, Scope, Serialize, SortingQueryParams,
+        WebError,
     };
     use iroha_data_model::prelude::{Domain, DomainId, FindAllDomains, FindDomainById, Metadata};
 
@@ -277,10 +361,17 @@ mod domains {
     async fn index(
         data: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
+        sorting: web::Query<SortingQueryParams>,
+        filter: web::Query<FilterQueryParams>,
     ) -> Result<web::Json<Paginated<Vec<DomainDTO>>>, WebError> {
         let paginated: Paginated<_> = data
             .iroha_client
-            .request_with_pagination(FindAllDomains::new(), pagination.into_inner().into())
+            .request_with_filter_and_pagination_and_sorting(
+                FindAllDomains::new(),
+                pagination.into_inner().into(),
+                sorting.into_inner().into_sorting()?,
+                filter.into_inner().into_predicate()?,
+            )
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
@@ -297,7 +388,8 @@ mod domains {
 mod assets {
     use super::{
         accounts::AccountIdInPath, asset_definitions::AssetDefinitionIdInPath, get, web, AppData,
-        Paginated, PaginationQueryParams, Scope, Serialize, WebError,
+        FilterQueryParams, Paginated, PaginationQueryParams, Scope, Serialize, SortingQueryParams,
+        WebError,
     };
     use iroha_data_model::prelude::{
         Asset, AssetId, AssetValue, AssetValueType, FindAllAssets, FindAssetById, Metadata,
@@ -366,10 +458,17 @@ mod assets {
     async fn index(
         data: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
+        sorting: web::Query<SortingQueryParams>,
+        filter: web::Query<FilterQueryParams>,
     ) -> Result<web::Json<Paginated<Vec<AssetDTO>>>, WebError> {
         let data: Paginated<_> = data
             .iroha_client
-            .request_with_pagination(FindAllAssets::new(), pagination.into_inner().into())
+            .request_with_filter_and_pagination_and_sorting(
+                FindAllAssets::new(),
+                pagination.into_inner().into(),
+                sorting.into_inner().into_sorting()?,
+                filter.into_inner().into_predicate()?,
+            )
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
@@ -399,8 +498,8 @@ mod assets {
 
 mod asset_definitions {
     use super::{
-        fmt, get, web, AppData, FromStr, Paginated, PaginationQueryParams, Scope, Serialize,
-        WebError,
+        fmt, get, web, AppData, FilterQueryParams, FromStr, Paginated, PaginationQueryParams,
+        Scope, Serialize, SortingQueryParams, WebError,
     };
     use iroha_data_model::{
         asset::Mintable,
@@ -481,10 +580,17 @@ mod asset_definitions {
     async fn index(
         data: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
+        sorting: web::Query<SortingQueryParams>,
+        filter: web::Query<FilterQueryParams>,
     ) -> Result<web::Json<Paginated<Vec<AssetDefinitionDTO>>>, WebError> {
         let data: Paginated<_> = data
             .iroha_client
-            .request_with_pagination(FindAllAssetsDefinitions::new(), pagination.0.into())
+            .request_with_filter_and_pagination_and_sorting(
+                FindAllAssetsDefinitions::new(),
+                pagination.0.into(),
+                sorting.0.into_sorting()?,
+                filter.0.into_predicate()?,
+            )
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
@@ -501,7 +607,10 @@ mod asset_definitions {
 }
 
 mod peer {
-    use super::{get, web, AppData, Paginated, PaginationQueryParams, Scope, Serialize, WebError};
+    use super::{
+        get, web, AppData, FilterQueryParams, Paginated, PaginationQueryParams, Scope, Serialize,
+        SortingQueryParams, WebError,
+    };
     use iroha_data_model::prelude::{FindAllPeers, Peer, PeerId};
     use iroha_telemetry::metrics::Status;
 
@@ -518,10 +627,17 @@ mod peer {
     async fn peers(
         data: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
+        sorting: web::Query<SortingQueryParams>,
+        filter: web::Query<FilterQueryParams>,
     ) -> Result<web::Json<Paginated<Vec<PeerDTO>>>, WebError> {
         let data: Paginated<_> = data
             .iroha_client
-            .request_with_pagination(FindAllPeers::new(), pagination.0.into())
+            .request_with_filter_and_pagination_and_sorting(
+                FindAllPeers::new(),
+                pagination.0.into(),
+                sorting.0.into_sorting()?,
+                filter.0.into_predicate()?,
+            )
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
@@ -542,7 +658,10 @@ mod peer {
 }
 
 mod roles {
-    use super::{get, web, AppData, Paginated, PaginationQueryParams, Scope, Serialize, WebError};
+    use super::{
+        get, web, AppData, FilterQueryParams, Paginated, PaginationQueryParams, Scope, Serialize,
+        SortingQueryParams, WebError,
+    };
     use iroha_data_model::prelude::{FindAllRoles, Role};
 
     #[derive(Serialize)]
@@ -558,11 +677,18 @@ mod roles {
     async fn index(
         app: web::Data<AppData>,
         pagination: web::Query<PaginationQueryParams>,
+        sorting: web::Query<SortingQueryParams>,
+        filter: web::Query<FilterQueryParams>,
     ) -> Result<web::Json<Paginated<Vec<RoleDTO>>>, WebError> {
         let data: Paginated<_> = app
             .iroha_client
             // TODO add an issue about absense of `FindAllRoles::new()`?
-            .request_with_pagination(FindAllRoles {}, pagination.0.into())
+            .request_with_filter_and_pagination_and_sorting(
+                FindAllRoles {},
+                pagination.0.into(),
+                sorting.0.into_sorting()?,
+                filter.0.into_predicate()?,
+            )
             .await
             .map_err(WebError::expect_iroha_any_error)?
             .try_into()?;
@@ -576,6 +702,160 @@ mod roles {
     }
 }
 
+mod blocks {
+    use super::{get, web, AppData, Paginated, PaginationQueryParams, Scope, Serialize, WebError};
+    use iroha_data_model::block::VersionedCommittedBlock;
+
+    /// A single committed block, flattened for JSON consumption.
+    #[derive(Serialize)]
+    pub struct BlockDTO {
+        height: u64,
+        timestamp: u128,
+        previous_block_hash: Option<String>,
+        transactions_count: usize,
+        rejected_transactions_count: usize,
+    }
+
+    impl From<&VersionedCommittedBlock> for BlockDTO {
+        fn from(block: &VersionedCommittedBlock) -> Self {
+            let payload = block.as_v1();
+            let rejected_transactions_count = payload
+                .transactions
+                .iter()
+                .filter(|tx| tx.error.is_some())
+                .count();
+
+            Self {
+                height: payload.header.height,
+                timestamp: payload.header.timestamp,
+                previous_block_hash: payload
+                    .header
+                    .previous_block_hash
+                    .map(|hash| hash.to_string()),
+                transactions_count: payload.transactions.len(),
+                rejected_transactions_count,
+            }
+        }
+    }
+
+    #[get("")]
+    async fn index(
+        data: web::Data<AppData>,
+        web::Query(pagination): web::Query<PaginationQueryParams>,
+    ) -> Result<web::Json<Paginated<Vec<BlockDTO>>>, WebError> {
+        let (page, total) = data.blocks.page(pagination.offset(), pagination.limit());
+        Ok(web::Json(Paginated {
+            data: page.iter().map(|block| BlockDTO::from(block.as_ref())).collect(),
+            pagination: super::pagination::PaginationDTO { total },
+        }))
+    }
+
+    #[get("/{height}")]
+    async fn show(
+        data: web::Data<AppData>,
+        height: web::Path<u64>,
+    ) -> Result<web::Json<BlockDTO>, WebError> {
+        let block = data
+            .blocks
+            .get(height.into_inner())
+            .ok_or(WebError::NotFound)?;
+        Ok(web::Json(BlockDTO::from(block.as_ref())))
+    }
+
+    pub fn service() -> Scope {
+        web::scope("/blocks").service(index).service(show)
+    }
+}
+
+mod events {
+    use super::{blocks::BlockDTO, get, web, AppData, Scope, Serialize};
+    use actix_web::{web::Bytes, HttpResponse};
+    use futures::StreamExt;
+    use tokio_stream::wrappers::BroadcastStream;
+
+    use crate::block_store::BlockStoreEvent;
+
+    /// A single live event, flattened for `text/event-stream` consumption.
+    #[derive(Serialize)]
+    #[serde(tag = "kind", rename_all = "snake_case")]
+    enum EventDTO {
+        Block(BlockDTO),
+        Transaction { hash: String, status: String },
+    }
+
+    impl From<&BlockStoreEvent> for EventDTO {
+        fn from(event: &BlockStoreEvent) -> Self {
+            match event {
+                BlockStoreEvent::Block(block) => Self::Block(BlockDTO::from(block.as_ref())),
+                BlockStoreEvent::Transaction { hash, status } => Self::Transaction {
+                    hash: hash.to_string(),
+                    status: format!("{status:?}"),
+                },
+            }
+        }
+    }
+
+    /// Streams every newly committed block and pipeline/transaction status
+    /// change as a `text/event-stream` event, so a frontend can show chain
+    /// activity live instead of polling `GET /blocks` or
+    /// `GET /transactions/{hash}`.
+    #[get("")]
+    async fn index(data: web::Data<AppData>) -> HttpResponse {
+        let updates = BroadcastStream::new(data.blocks.subscribe()).filter_map(|item| async move {
+            let event = item.ok()?;
+            let payload = serde_json::to_string(&EventDTO::from(event.as_ref())).ok()?;
+            Some(Ok::<_, std::convert::Infallible>(Bytes::from(format!(
+                "data: {payload}\n\n"
+            ))))
+        });
+
+        HttpResponse::Ok()
+            .content_type("text/event-stream")
+            .streaming(updates)
+    }
+
+    pub fn service() -> Scope {
+        web::scope("/events").service(index)
+    }
+}
+
+mod transactions {
+    use super::{get, web, AppData, Scope, Serialize, WebError};
+    use iroha_crypto::Hash;
+    use std::str::FromStr;
+
+    /// A single transaction, together with its rejection reason, if any.
+    #[derive(Serialize)]
+    pub struct TransactionDTO {
+        hash: String,
+        block_height: u64,
+        authority: String,
+        rejection_reason: Option<String>,
+    }
+
+    #[get("/{hash}")]
+    async fn show(
+        data: web::Data<AppData>,
+        hash: web::Path<String>,
+    ) -> Result<web::Json<TransactionDTO>, WebError> {
+        let hash = Hash::from_str(&hash.into_inner())
+            .map_err(|err| WebError::InvalidPath(format!("Bad transaction hash: {err}")))?;
+        let (block_height, transaction) =
+            data.blocks.get_transaction(hash).ok_or(WebError::NotFound)?;
+
+        Ok(web::Json(TransactionDTO {
+            hash: transaction.hash().to_string(),
+            block_height,
+            authority: transaction.payload().authority.to_string(),
+            rejection_reason: transaction.error.as_ref().map(ToString::to_string),
+        }))
+    }
+
+    pub fn service() -> Scope {
+        web::scope("/transactions").service(show)
+    }
+}
+
 async fn default_route() -> impl Responder {
     HttpResponse::NotFound().body("Not Found")
 }
@@ -587,32 +867,92 @@ async fn root_health_check() -> impl Responder {
 
 pub struct ServerInitData {
     iroha_client: Arc<iroha_client::client::Client>,
+    host: String,
+    tls: Option<crate::tls::TlsConfig>,
+    client_wrap: Option<IrohaClientWrap>,
 }
 
 impl ServerInitData {
     pub fn new(iroha_client: Arc<iroha_client::client::Client>) -> Self {
-        Self { iroha_client }
+        Self {
+            iroha_client,
+            host: "127.0.0.1".to_owned(),
+            tls: None,
+            client_wrap: None,
+        }
+    }
+
+    /// Overrides the address `server()` binds to. Defaults to `127.0.0.1`,
+    /// i.e. loopback-only.
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = host.into();
+        self
+    }
+
+    /// Enables HTTPS termination, so the explorer backend can be exposed
+    /// directly without a reverse proxy in front of it.
+    pub fn with_tls(mut self, tls: crate::tls::TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Supplies a pre-built [`IrohaClientWrap`] — e.g. one configured via
+    /// [`crate::iroha_client_wrap::IrohaClientWrapBuilder`] with interceptors,
+    /// a retry policy or a non-default concurrency limit — for `server()` to
+    /// share across every worker instead of building one from defaults.
+    pub fn with_iroha_client_wrap(mut self, client_wrap: IrohaClientWrap) -> Self {
+        self.client_wrap = Some(client_wrap);
+        self
     }
 }
 
-/// Initializes a server listening on `127.0.0.1:<port>`. It should be awaited to be actually started.
+/// Initializes a server listening on `<host>:<port>`, plaintext unless
+/// [`ServerInitData::with_tls`] was used. It should be awaited to be
+/// actually started. The returned [`crate::block_store::SyncHandle`] should
+/// be `.cancel()`-ed once the server has stopped, so the background sync
+/// tasks it started drop cleanly instead of outliving it.
 pub fn server(
-    ServerInitData { iroha_client }: ServerInitData,
+    ServerInitData {
+        iroha_client,
+        host,
+        tls,
+        client_wrap,
+    }: ServerInitData,
     port: u16,
-) -> color_eyre::Result<actix_server::Server> {
-    let server = HttpServer::new(move || {
-        let client_wrap = crate::iroha_client_wrap::IrohaClientWrap::new(iroha_client.clone());
-        let app_data = web::Data::new(AppData::new(client_wrap));
+) -> color_eyre::Result<(actix_server::Server, crate::block_store::SyncHandle)> {
+    let blocks = BlockStore::new();
+    let sync_handle = blocks.clone().spawn_sync(iroha_client.clone());
+
+    let metrics_handle = web::Data::new(crate::metrics::install_recorder()?);
+
+    // Built once and `.clone()`d into every worker below, so the concurrency
+    // semaphore and retry/interceptor config actually apply server-wide
+    // instead of being re-created (and re-split) per worker. Uses the
+    // caller-supplied wrap (with whatever interceptors/retry policy/
+    // concurrency limit it was built with) if `ServerInitData::with_iroha_client_wrap`
+    // was called, otherwise falls back to all-defaults.
+    let client_wrap = client_wrap
+        .unwrap_or_else(|| IrohaClientWrap::builder(iroha_client.clone()).build());
+    crate::metrics::spawn_status_poller(client_wrap.clone(), std::time::Duration::from_secs(10));
+
+    let http_server = HttpServer::new(move || {
+        let app_data = web::Data::new(AppData::new(client_wrap.clone(), blocks.clone()));
 
         App::new()
             .app_data(app_data)
+            .app_data(metrics_handle.clone())
             .app_data(web::QueryConfig::default().error_handler(|err, _req| {
-                WebError::BadRequest(format!("Bad query: {err}")).into()
+                WebError::InvalidQuery(format!("Bad query: {err}")).into()
             }))
+            .wrap(crate::metrics::RequestMetrics)
             .wrap(super::logger::TracingLogger::default())
             .wrap(middleware::NormalizePath::new(
                 middleware::TrailingSlash::Trim,
             ))
+            .wrap(crate::deadline::RequestDeadline::new(
+                std::time::Duration::from_secs(30),
+            ))
+            .service(crate::metrics::service())
             .service(
                 web::scope("/api/v1")
                     .service(root_health_check)
@@ -621,12 +961,20 @@ pub fn server(
                     .service(assets::service())
                     .service(asset_definitions::service())
                     .service(roles::service())
-                    .service(peer::service()),
+                    .service(peer::service())
+                    .service(blocks::service())
+                    .service(events::service())
+                    .service(transactions::service()),
             )
             .default_service(web::route().to(default_route))
-    })
-    .bind(("127.0.0.1", port))?
-    .run();
+    });
+
+    let server = match tls {
+        Some(tls) => http_server
+            .bind_rustls_0_23((host.as_str(), port), tls.into_server_config()?)?
+            .run(),
+        None => http_server.bind((host.as_str(), port))?.run(),
+    };
 
-    Ok(server)
+    Ok((server, sync_handle))
 }
@@ -0,0 +1,86 @@
+//! Pagination query params and the [`Paginated`] response wrapper shared by
+//! every `index` endpoint.
+
+use iroha_client::client::QueryResult;
+use iroha_data_model::query::Pagination;
+use serde::{Deserialize, Serialize};
+
+/// Query params accepted by every `index` endpoint for paging a collection.
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+pub struct PaginationQueryParams {
+    pub page: Option<std::num::NonZeroU32>,
+    pub per_page: Option<std::num::NonZeroU32>,
+}
+
+impl From<PaginationQueryParams> for Pagination {
+    fn from(params: PaginationQueryParams) -> Self {
+        Self {
+            // `page` and `per_page` are entirely client-controlled (e.g.
+            // `?page=100000&per_page=100000`); a plain `*` here overflows
+            // `u32` and panics in debug / wraps to a bogus offset in
+            // release. Saturate to `u32::MAX` instead — an absurdly large
+            // page just comes back empty.
+            start: params
+                .page
+                .zip(params.per_page)
+                .map(|(page, per_page)| (page.get() - 1).saturating_mul(per_page.get()))
+                .and_then(std::num::NonZeroU32::new),
+            limit: params.per_page,
+        }
+    }
+}
+
+impl PaginationQueryParams {
+    /// The number of items to skip before the requested page starts, for
+    /// collections not backed by an Iroha query (e.g. [`crate::block_store`]).
+    pub fn offset(&self) -> usize {
+        self.page
+            .zip(self.per_page)
+            .map(|(page, per_page)| {
+                (page.get() as usize - 1).saturating_mul(per_page.get() as usize)
+            })
+            .unwrap_or(0)
+    }
+
+    /// The maximum number of items the requested page may contain.
+    pub fn limit(&self) -> usize {
+        self.per_page.map_or(usize::MAX, |per_page| per_page.get() as usize)
+    }
+}
+
+/// A page of `T`, together with enough metadata for a client to compute the
+/// total number of pages.
+#[derive(Serialize)]
+pub struct Paginated<T> {
+    pub data: T,
+    pub pagination: PaginationDTO,
+}
+
+/// Pagination metadata returned alongside a page of data.
+#[derive(Serialize)]
+pub struct PaginationDTO {
+    pub total: u64,
+}
+
+impl<T> Paginated<T> {
+    /// Transforms the paginated data, keeping the pagination metadata as-is.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Paginated<U> {
+        Paginated {
+            data: f(self.data),
+            pagination: self.pagination,
+        }
+    }
+}
+
+impl<T> TryFrom<QueryResult<T>> for Paginated<T> {
+    type Error = color_eyre::Report;
+
+    fn try_from(result: QueryResult<T>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            data: result.output,
+            pagination: PaginationDTO {
+                total: result.total,
+            },
+        })
+    }
+}
@@ -0,0 +1,33 @@
+//! Sorting query params accepted by every `index` endpoint.
+
+use std::str::FromStr;
+
+use iroha_data_model::{prelude::Name, query::Sorting};
+use serde::Deserialize;
+
+use super::WebError;
+
+/// Query params accepted by every `index` endpoint for ordering a
+/// collection: `?sort_by=<metadata key>` orders entries by that key's value
+/// in their metadata.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct SortingQueryParams {
+    pub sort_by: Option<String>,
+}
+
+impl SortingQueryParams {
+    /// Compiles the `sort_by` query param into a [`Sorting`].
+    pub fn into_sorting(self) -> Result<Sorting, WebError> {
+        let sort_by_metadata_key = self
+            .sort_by
+            .map(|key| {
+                Name::from_str(&key)
+                    .map_err(|err| WebError::InvalidQuery(format!("Bad sort_by: {err}")))
+            })
+            .transpose()?;
+
+        Ok(Sorting {
+            sort_by_metadata_key,
+        })
+    }
+}